@@ -1,20 +1,86 @@
+#![allow(clippy::needless_return)]
+
 use proc_macro::TokenStream;
-use quote::{format_ident, quote};
+use quote::quote;
 
 use syn::{
     parse_macro_input,
+    parse_quote,
     DeriveInput,
+    Generics,
 };
 
-#[proc_macro_derive(Component)]
+fn with_boxing_bounds(generics: &Generics) -> Generics {
+    let mut generics = generics.clone();
+
+    for param in generics.type_params_mut() {
+        param.bounds.push(parse_quote!('static));
+    }
+
+    return generics;
+}
+
+fn parse_options(input: &DeriveInput, attribute: &str, options: &[&str]) -> syn::Result<Vec<String>> {
+    let mut enabled = Vec::new();
+
+    for attr in &input.attrs {
+        if attr.path().is_ident(attribute) {
+            attr.parse_nested_meta(|meta| {
+                for option in options {
+                    if meta.path.is_ident(option) {
+                        enabled.push(option.to_string());
+
+                        return Ok(());
+                    }
+                }
+
+                return Err(meta.error(format!("unsupported {} attribute", attribute)));
+            })?;
+        }
+    }
+
+    return Ok(enabled);
+}
+
+#[proc_macro_derive(Component, attributes(component))]
 pub fn derive_component(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
 
     let component = &input.ident;
-    let pool = format_ident!("{}Pool", component);
+
+    let options = match parse_options(&input, "component", &["unique", "clone"]) {
+        Ok(options) => options,
+        Err(error) => return TokenStream::from(error.to_compile_error()),
+    };
+
+    let unique = options.iter().any(|option| option == "unique");
+    let clone = options.iter().any(|option| option == "clone");
+
+    let generics = with_boxing_bounds(&input.generics);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let clone_box = if clone {
+        quote! {
+            fn clone_box(&self) -> Option<Box<dyn AnyComponent>> {
+                return Some(Box::new(<Self as Clone>::clone(self)));
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let is_unique = if unique {
+        quote! {
+            fn is_unique(&self) -> bool {
+                return true;
+            }
+        }
+    } else {
+        quote! {}
+    };
 
     let expanded = quote! {
-        impl AnyComponent for #component {
+        impl #impl_generics AnyComponent for #component #ty_generics #where_clause {
             fn id(&self) -> ComponentID {
                 let hasher = RandomState::with_seed(0);
 
@@ -23,7 +89,7 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
                 return hasher.hash_one(id_str);
             }
 
-            fn type_id() -> ComponentID {
+            fn component_id() -> ComponentID {
                 let hasher = RandomState::with_seed(0);
 
                 let id_str = std::any::type_name::<Self>();
@@ -46,6 +112,77 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
             fn into_box(self) -> Box<dyn AnyComponent> {
                 return Box::new(self);
             }
+
+            #clone_box
+
+            #is_unique
+        }
+    };
+
+    TokenStream::from(expanded)
+}
+
+#[proc_macro_derive(Event, attributes(event))]
+pub fn derive_event(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let event = &input.ident;
+
+    let options = match parse_options(&input, "event", &["clone"]) {
+        Ok(options) => options,
+        Err(error) => return TokenStream::from(error.to_compile_error()),
+    };
+
+    let clone = options.iter().any(|option| option == "clone");
+
+    let generics = with_boxing_bounds(&input.generics);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let clone_box = if clone {
+        quote! {
+            fn clone_box(&self) -> Option<Box<dyn AnyEvent>> {
+                return Some(Box::new(<Self as Clone>::clone(self)));
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let expanded = quote! {
+        impl #impl_generics AnyEvent for #event #ty_generics #where_clause {
+            fn id(&self) -> EventID {
+                let hasher = RandomState::with_seed(0);
+
+                let id_str = std::any::type_name::<Self>();
+
+                return hasher.hash_one(id_str);
+            }
+
+            fn event_id() -> EventID {
+                let hasher = RandomState::with_seed(0);
+
+                let id_str = std::any::type_name::<Self>();
+
+                return hasher.hash_one(id_str);
+            }
+
+            fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+                return self as &mut dyn std::any::Any;
+            }
+
+            fn as_any(&self) -> &dyn std::any::Any {
+                return self as &dyn std::any::Any;
+            }
+
+            fn into_any(self: Box<Self>) -> Box<dyn std::any::Any> {
+                return self;
+            }
+
+            fn into_box(self) -> Box<dyn AnyEvent> {
+                return Box::new(self);
+            }
+
+            #clone_box
         }
     };
 
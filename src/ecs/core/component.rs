@@ -1,8 +1,10 @@
 use std::any::Any;
-use ahash::AHashSet;
 
 pub use macros::Component;
-pub use ahash::RandomState;
+pub use ahash::{
+    AHashSet,
+    RandomState
+};
 
 pub type ComponentID = u64;
 pub type ComponentIndex = usize;
@@ -11,12 +13,23 @@ pub type ArchetypeID = u64;
 pub type ArchetypeIndex = usize;
 
 pub trait AnyComponent {
-    fn type_id() -> ComponentID where Self: Sized;
+    fn component_id() -> ComponentID where Self: Sized;
 
     fn id(&self) -> ComponentID;
 
     fn into_box(self) -> Box<dyn AnyComponent>;
 
+    /// Duplicates a boxed component, or returns `None` if the component cannot be cloned.
+    /// The derive only implements it for components marked `#[component(clone)]`, which must then be `Clone`.
+    fn clone_box(&self) -> Option<Box<dyn AnyComponent>> {
+        return None;
+    }
+
+    /// Whether at most one entity may own this component. Set with `#[component(unique)]` on the derive.
+    fn is_unique(&self) -> bool {
+        return false;
+    }
+
     fn as_any(&self) -> &dyn Any;
 
     fn as_any_mut(&mut self) -> &mut dyn Any;
@@ -24,14 +37,85 @@ pub trait AnyComponent {
     fn into_any(self: Box<Self>) -> Box<dyn Any>;
 }
 
+impl dyn AnyComponent {
+    pub fn is<T: AnyComponent + 'static>(&self) -> bool {
+        return self.as_any().is::<T>();
+    }
+
+    pub fn downcast_ref<T: AnyComponent + 'static>(&self) -> Option<&T> {
+        return self.as_any().downcast_ref::<T>();
+    }
+
+    pub fn downcast_mut<T: AnyComponent + 'static>(&mut self) -> Option<&mut T> {
+        return self.as_any_mut().downcast_mut::<T>();
+    }
+}
+
+/// Builds the `AHashSet<ComponentID>` of the listed component types.
+///
+/// ```
+/// use hnz::components;
+/// use hnz::ecs::core::component::*;
+///
+/// #[derive(Component)]
+/// struct Position;
+///
+/// #[derive(Component)]
+/// struct Velocity;
+///
+/// let expected = [Position::component_id(), Velocity::component_id()].into_iter().collect::<AHashSet<ComponentID>>();
+///
+/// assert_eq!(components![Position, Velocity], expected);
+/// assert_eq!(components![Position, Velocity,], expected);
+/// assert!(components![].is_empty());
+/// ```
+#[macro_export]
+macro_rules! components {
+    ($($component:ty),* $(,)?) => {{
+        let mut components = $crate::ecs::core::component::AHashSet::<$crate::ecs::core::component::ComponentID>::new();
+
+        $(
+            components.insert(<$component as $crate::ecs::core::component::AnyComponent>::component_id());
+        )*
+
+        components
+    }};
+}
+
 pub fn as_archetype(components: &AHashSet<ComponentID>) -> ArchetypeID {
-    let mut hasher = RandomState::with_seed(0);
+    let hasher = RandomState::with_seed(0);
+
+    let mut ids = components.iter().cloned().collect::<Vec<ComponentID>>();
+    ids.sort_unstable();
+
+    return hasher.hash_one(ids);
+}
 
-    let mut id = 0u128;
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    for component in components {
-        id += *component as u128;
+    #[test]
+    fn archetype_id_ignores_insertion_order() {
+        let mut forward = AHashSet::new();
+        let mut backward = AHashSet::new();
+
+        for component in 0..64 {
+            forward.insert(component);
+            backward.insert(63 - component);
+        }
+
+        assert_eq!(as_archetype(&forward), as_archetype(&backward));
     }
 
-    return hasher.hash_one(id);
+    #[test]
+    fn archetype_id_does_not_add_component_ids() {
+        let a: ComponentID = 0x1234;
+        let b: ComponentID = 0x5678;
+
+        let pair = [a, b].into_iter().collect::<AHashSet<ComponentID>>();
+        let sum = [a + b].into_iter().collect::<AHashSet<ComponentID>>();
+
+        assert_ne!(as_archetype(&pair), as_archetype(&sum));
+    }
 }
\ No newline at end of file
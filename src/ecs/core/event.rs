@@ -0,0 +1,26 @@
+use std::any::Any;
+
+pub use macros::Event;
+pub use ahash::RandomState;
+
+pub type EventID = u64;
+
+pub trait AnyEvent {
+    fn event_id() -> EventID where Self: Sized;
+
+    fn id(&self) -> EventID;
+
+    fn into_box(self) -> Box<dyn AnyEvent>;
+
+    /// Duplicates a boxed event, or returns `None` if the event cannot be cloned.
+    /// The derive only implements it for events marked `#[event(clone)]`, which must then be `Clone`.
+    fn clone_box(&self) -> Option<Box<dyn AnyEvent>> {
+        return None;
+    }
+
+    fn as_any(&self) -> &dyn Any;
+
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any>;
+}
\ No newline at end of file
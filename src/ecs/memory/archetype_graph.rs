@@ -34,6 +34,12 @@ impl Column {
     }
 }
 
+impl Default for Column {
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
 pub struct ArchetypeGraph {
     columns: AHashMap<usize, Column>,
     map: AHashMap<ArchetypeID, usize>,
@@ -48,6 +54,13 @@ impl ArchetypeGraph {
     }
 
     pub fn add_archetype(&mut self, archetype: Archetype) {
+        #[cfg(debug_assertions)]
+        if let Some(existing) = self.get(archetype.id) {
+            if existing.components != archetype.components {
+                panic!("Archetype ID collision: {:?} and {:?} both map to the archetype {}", existing.components, archetype.components, archetype.id);
+            }
+        }
+
         if !self.map.contains_key(&archetype.id) {
             let size = archetype.components.len();
             let id = archetype.id;
@@ -116,4 +129,44 @@ impl ArchetypeGraph {
 
         return None;
     }
+}
+
+impl Default for ArchetypeGraph {
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn archetype(id: ArchetypeID, components: &[ComponentID]) -> Archetype {
+        return Archetype {
+            id,
+            components: components.iter().cloned().collect(),
+            next: vec![],
+            prev: vec![],
+        };
+    }
+
+    #[test]
+    fn readding_an_identical_archetype_is_ignored() {
+        let mut graph = ArchetypeGraph::new();
+
+        graph.add_archetype(archetype(7, &[1, 2]));
+        graph.add_archetype(archetype(7, &[2, 1]));
+
+        assert_eq!(graph.get(7).unwrap().components, [1, 2].into_iter().collect());
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "Archetype ID collision")]
+    fn colliding_archetype_ids_panic() {
+        let mut graph = ArchetypeGraph::new();
+
+        graph.add_archetype(archetype(7, &[1, 2]));
+        graph.add_archetype(archetype(7, &[3]));
+    }
 }
\ No newline at end of file
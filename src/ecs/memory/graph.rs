@@ -1,4 +1,3 @@
-use std::sync::Arc;
 use ahash::{
     AHashMap,
     AHashSet
@@ -37,6 +36,7 @@ impl Archetype {
 }
 
 struct MemoryColumn {
+    #[allow(dead_code)]
     size: usize,
     archetypes: Vec<Archetype>,
     map: AHashMap<ArchetypeID, ArchetypeIndex>,
@@ -45,17 +45,14 @@ struct MemoryColumn {
 impl MemoryColumn {
     pub fn new(size: usize) -> Self {
         if size == 0 {
+            let root = Archetype::new(AHashSet::new());
+
             let mut map = AHashMap::new();
-            map.insert(0, 0);
+            map.insert(root.id, 0);
 
             return MemoryColumn {
                 size: 0,
-                archetypes: vec![Archetype {
-                    id: 0,
-                    components: AHashSet::new(),
-                    next: vec![],
-                    prev: vec![]
-                }],
+                archetypes: vec![root],
                 map,
             };
         }
@@ -67,13 +64,15 @@ impl MemoryColumn {
         };
     }
 
+    #[allow(dead_code)]
     pub fn add_archetype(&mut self, archetype: Archetype) {
         if !self.map.contains_key(&archetype.id) {
-            self.map.insert(archetype.id, self.archetypes.len() - 1);
+            self.map.insert(archetype.id, self.archetypes.len());
             self.archetypes.push(archetype);
         }
     }
 
+    #[allow(dead_code)]
     pub fn update_previous_and_next(&mut self, archetype: ArchetypeID, previous: &mut MemoryColumn, next: &mut MemoryColumn) {
         self.update_previous(archetype, previous);
         self.update_next(archetype, next);
@@ -107,8 +106,10 @@ impl MemoryColumn {
 }
 
 pub struct MemoryGraph {
+    #[allow(dead_code)]
     entities: AHashMap<Entity, ArchetypeID>,
 
+    #[allow(dead_code)]
     columns: Vec<MemoryColumn>,
 
     map: AHashMap<ArchetypeID, usize>,
@@ -123,13 +124,54 @@ impl MemoryGraph {
         }
     }
 
+    #[allow(dead_code)]
     unsafe fn update_archetype(&mut self, archetype: ArchetypeID){
-        if let Some(size) = self.map.get(&archetype) {
+        if let Some(_size) = self.map.get(&archetype) {
 
         }
     }
 
-    pub fn add_component(&mut self, entity: Entity, component: ComponentID) {}
+    pub fn add_component(&mut self, _entity: Entity, _component: ComponentID) {}
+
+    pub fn remove_component(&mut self, _entity: Entity, _component: ComponentID) {}
+}
+
+impl Default for MemoryGraph {
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn column_maps_archetypes_to_their_index() {
+        let mut column = MemoryColumn::new(1);
+
+        let first = Archetype::new([1].into_iter().collect());
+        let second = Archetype::new([2].into_iter().collect());
 
-    pub fn remove_component(&mut self, entity: Entity, component: ComponentID) {}
+        let (first_id, second_id) = (first.id, second.id);
+
+        column.add_archetype(first);
+        assert_eq!(column.map.get(&first_id), Some(&0));
+
+        column.add_archetype(second);
+        assert_eq!(column.map.get(&second_id), Some(&1));
+
+        for (id, index) in &column.map {
+            assert_eq!(column.archetypes[*index].id, *id);
+        }
+    }
+
+    #[test]
+    fn root_archetype_uses_the_empty_set_id() {
+        let column = MemoryColumn::new(0);
+        let root = as_archetype(&AHashSet::new());
+
+        assert_eq!(column.archetypes[0].id, root);
+        assert_eq!(column.map.get(&root), Some(&0));
+    }
 }
\ No newline at end of file
@@ -1 +1,3 @@
-pub mod ecs;
+#![allow(clippy::needless_return)]
+
+pub mod ecs;
\ No newline at end of file
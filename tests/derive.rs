@@ -0,0 +1,136 @@
+use hnz::ecs::core::component::*;
+use hnz::ecs::core::event::*;
+
+#[derive(Component)]
+struct NoClone {
+    x: f32,
+}
+
+#[derive(Component, Clone)]
+#[component(clone)]
+struct Position {
+    x: f32,
+}
+
+#[derive(Component)]
+#[component(unique)]
+struct Camera;
+
+#[derive(Component)]
+struct Wrapper<T> {
+    inner: T,
+}
+
+#[derive(Component, Clone)]
+#[component(clone)]
+struct Shared<T: Clone> {
+    inner: T,
+}
+
+#[derive(Event)]
+struct Closed;
+
+#[derive(Event, Clone)]
+#[event(clone)]
+struct Damage {
+    amount: u32,
+}
+
+#[derive(Event)]
+enum Input {
+    Key(u32),
+    Mouse(f32, f32),
+}
+
+#[test]
+fn clone_box_is_none_without_the_clone_attribute() {
+    let component = NoClone { x: 1.0 }.into_box();
+
+    assert!(component.clone_box().is_none());
+    assert_eq!(component.downcast_ref::<NoClone>().unwrap().x, 1.0);
+}
+
+#[test]
+fn clone_box_copies_components_marked_clone() {
+    let component = Position { x: 2.0 }.into_box();
+    let clone = component.clone_box().unwrap();
+
+    assert_eq!(clone.id(), Position::component_id());
+    assert_eq!(clone.downcast_ref::<Position>().unwrap().x, 2.0);
+}
+
+#[test]
+fn downcast_mut_writes_through_the_box() {
+    let mut component = Position { x: 1.0 }.into_box();
+
+    component.downcast_mut::<Position>().unwrap().x = 4.0;
+
+    assert!(component.is::<Position>());
+    assert_eq!(component.downcast_ref::<Position>().unwrap().x, 4.0);
+}
+
+#[test]
+fn downcasts_to_the_wrong_type_fail() {
+    let mut component = Position { x: 1.0 }.into_box();
+
+    assert!(!component.is::<NoClone>());
+    assert!(component.downcast_ref::<NoClone>().is_none());
+    assert!(component.downcast_mut::<NoClone>().is_none());
+}
+
+#[test]
+fn is_unique_follows_the_unique_attribute() {
+    assert!(Camera.is_unique());
+    assert!(!Position { x: 0.0 }.is_unique());
+}
+
+#[test]
+fn event_clone_box_is_none_without_the_clone_attribute() {
+    assert!(Closed.into_box().clone_box().is_none());
+}
+
+#[test]
+fn event_clone_box_copies_events_marked_clone() {
+    let event = Damage { amount: 3 }.into_box();
+    let clone = event.clone_box().unwrap();
+
+    assert_eq!(clone.id(), Damage::event_id());
+    assert_eq!(clone.into_any().downcast::<Damage>().unwrap().amount, 3);
+}
+
+#[test]
+fn generic_components_get_one_id_per_instantiation() {
+    assert_ne!(Wrapper::<u8>::component_id(), Wrapper::<u16>::component_id());
+    assert_eq!(Wrapper { inner: 1u8 }.id(), Wrapper::<u8>::component_id());
+}
+
+#[test]
+fn generic_components_box_without_clone() {
+    let component: Box<dyn AnyComponent> = Box::new(Wrapper { inner: NoClone { x: 4.0 } });
+
+    assert!(component.clone_box().is_none());
+    assert_eq!(component.downcast_ref::<Wrapper<NoClone>>().unwrap().inner.x, 4.0);
+}
+
+#[test]
+fn generic_components_marked_clone_can_be_cloned() {
+    let component = Shared { inner: 5u32 }.into_box();
+    let clone = component.clone_box().unwrap();
+
+    assert_eq!(clone.downcast_ref::<Shared<u32>>().unwrap().inner, 5);
+    assert!(!clone.is::<Shared<u64>>());
+}
+
+#[test]
+fn enum_events_box_and_downcast() {
+    let events = vec![Input::Key(42).into_box(), Input::Mouse(1.0, 2.0).into_box()];
+
+    for event in &events {
+        assert_eq!(event.id(), Input::event_id());
+    }
+
+    let mut events = events.into_iter().map(|event| *event.into_any().downcast::<Input>().unwrap());
+
+    assert!(matches!(events.next(), Some(Input::Key(42))));
+    assert!(matches!(events.next(), Some(Input::Mouse(x, y)) if x == 1.0 && y == 2.0));
+}
\ No newline at end of file